@@ -535,6 +535,18 @@ pub struct Common {
     pub skip_schema_validation: bool,
     #[env_config(name = "ZO_FEATURE_FULLTEXT_EXTRA_FIELDS", default = "")]
     pub feature_fulltext_extra_fields: String,
+    #[env_config(
+        name = "ZO_FULL_TEXT_SEARCH_TRIM_TERM",
+        default = true,
+        help = "Trim leading and trailing whitespace from match_all search terms."
+    )]
+    pub full_text_search_trim_term: bool,
+    #[env_config(
+        name = "ZO_FULL_TEXT_SEARCH_RAW_TRIM_TERM",
+        default = false,
+        help = "Trim leading and trailing whitespace from match_all_raw and match_all_raw_ignore_case search terms."
+    )]
+    pub full_text_search_raw_trim_term: bool,
     #[env_config(name = "ZO_FEATURE_DISTINCT_EXTRA_FIELDS", default = "")]
     pub feature_distinct_extra_fields: String,
     #[env_config(name = "ZO_FEATURE_QUICK_MODE_FIELDS", default = "")]
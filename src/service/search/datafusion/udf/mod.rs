@@ -33,6 +33,18 @@ pub(crate) mod time_range_udf;
 pub(crate) mod to_arr_string_udf;
 pub(crate) mod transform_udf;
 
+/// SQL function name of match_all, expanded by the search rewriter.
+pub(crate) const MATCH_ALL_UDF_NAME: &str = "match_all";
+/// SQL function name of match_all_raw, expanded by the search rewriter.
+pub(crate) const MATCH_ALL_RAW_UDF_NAME: &str = "match_all_raw";
+/// SQL function name of match_all_raw_ignore_case, expanded by the search rewriter.
+pub(crate) const MATCH_ALL_RAW_IGNORE_CASE_UDF_NAME: &str = "match_all_raw_ignore_case";
+/// All match_all variants recognised by the search rewriter.
+pub(crate) const MATCH_ALL_UDF_NAMES: [&str; 3] = [
+    MATCH_ALL_UDF_NAME,
+    MATCH_ALL_RAW_UDF_NAME,
+    MATCH_ALL_RAW_IGNORE_CASE_UDF_NAME,
+];
 /// The name of the match UDF given to DataFusion.
 pub(crate) const MATCH_UDF_NAME: &str = "str_match";
 /// The name of the match_ignore_case UDF given to DataFusion.
@@ -44,15 +56,15 @@ pub(crate) const REGEX_NOT_MATCH_UDF_NAME: &str = "re_not_match";
 
 pub(crate) const DEFAULT_FUNCTIONS: [ZoFunction; 7] = [
     ZoFunction {
-        name: "match_all_raw",
+        name: MATCH_ALL_RAW_UDF_NAME,
         text: "match_all_raw('v')",
     },
     ZoFunction {
-        name: "match_all_raw_ignore_case",
+        name: MATCH_ALL_RAW_IGNORE_CASE_UDF_NAME,
         text: "match_all_raw_ignore_case('v')",
     },
     ZoFunction {
-        name: "match_all",
+        name: MATCH_ALL_UDF_NAME,
         text: "match_all('v')",
     },
     ZoFunction {
@@ -84,3 +96,19 @@ pub fn stringify_json_value(field: &json::Value) -> String {
         _ => json::to_string(field).expect("failed to stringify json field"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_functions_match_all_text() {
+        for name in MATCH_ALL_UDF_NAMES {
+            let func = DEFAULT_FUNCTIONS
+                .iter()
+                .find(|f| f.name == name)
+                .expect("match_all variant missing from DEFAULT_FUNCTIONS");
+            assert_eq!(func.text, format!("{name}('v')"));
+        }
+    }
+}
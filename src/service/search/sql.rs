@@ -25,7 +25,7 @@ use config::{
     utils::sql::is_aggregate_query,
     QUERY_WITH_NO_LIMIT, QUICK_MODEL_FIELDS,
 };
-use datafusion::arrow::datatypes::{DataType, Schema};
+use datafusion::arrow::datatypes::{DataType, FieldRef, Schema};
 use hashbrown::HashSet;
 use infra::{
     errors::{Error, ErrorCodes},
@@ -40,7 +40,16 @@ use regex::Regex;
 use serde::Serialize;
 use sqlparser::ast::{BinaryOperator, Expr, Ident};
 
-use crate::{common::meta::stream::StreamParams, service::search::match_source};
+use crate::{
+    common::meta::stream::StreamParams,
+    service::search::{
+        datafusion::udf::{
+            MATCH_ALL_RAW_IGNORE_CASE_UDF_NAME, MATCH_ALL_RAW_UDF_NAME, MATCH_ALL_UDF_NAME,
+            MATCH_ALL_UDF_NAMES,
+        },
+        match_source,
+    },
+};
 
 const SQL_DELIMITERS: [u8; 12] = [
     b' ', b'*', b'(', b')', b'<', b'>', b',', b';', b'=', b'!', b'\r', b'\n',
@@ -55,10 +64,15 @@ pub static RE_WHERE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i) where (.*)").u
 pub static RE_HISTOGRAM: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(?i)histogram\(([^\)]*)\)").unwrap());
 static RE_MATCH_ALL_RAW: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"(?i)match_all_raw\('([^']*)'\)").unwrap());
-static RE_MATCH_ALL_RAW_IGNORE_CASE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"(?i)match_all_raw_ignore_case\('([^']*)'\)").unwrap());
-static RE_MATCH_ALL: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)match_all\('([^']*)'\)").unwrap());
+    Lazy::new(|| Regex::new(&format!(r"(?i){MATCH_ALL_RAW_UDF_NAME}\('([^']*)'\)")).unwrap());
+static RE_MATCH_ALL_RAW_IGNORE_CASE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"(?i){MATCH_ALL_RAW_IGNORE_CASE_UDF_NAME}\('([^']*)'\)"
+    ))
+    .unwrap()
+});
+static RE_MATCH_ALL: Lazy<Regex> =
+    Lazy::new(|| Regex::new(&format!(r"(?i){MATCH_ALL_UDF_NAME}\('([^']*)'\)")).unwrap());
 
 #[derive(Clone, Debug, Serialize)]
 pub struct Sql {
@@ -335,18 +349,17 @@ impl Sql {
         for token in &where_tokens {
             let tokens = split_sql_token_unwrap_brace(token);
             for token in &tokens {
-                if !token.to_lowercase().starts_with("match_all") {
+                // all match_all variants share the match_all prefix
+                if !token.to_lowercase().starts_with(MATCH_ALL_UDF_NAME) {
                     continue;
                 }
-                for cap in RE_MATCH_ALL_RAW.captures_iter(token) {
-                    fulltext.push((cap[0].to_string(), cap[1].to_string()));
-                }
-                for cap in RE_MATCH_ALL_RAW_IGNORE_CASE.captures_iter(token) {
-                    fulltext.push((cap[0].to_string(), cap[1].to_lowercase()));
-                }
-                for cap in RE_MATCH_ALL.captures_iter(token) {
-                    indexed_text.push((cap[0].to_string(), cap[1].to_lowercase())); // since `terms` are indexed in lowercase
-                }
+                extract_match_all_terms(
+                    token,
+                    cfg.common.full_text_search_trim_term,
+                    cfg.common.full_text_search_raw_trim_term,
+                    &mut fulltext,
+                    &mut indexed_text,
+                );
             }
         }
 
@@ -359,20 +372,17 @@ impl Sql {
             false
         };
 
+        // full text search fields, matched against the schema case-insensitively
+        let fts_search_fields = get_fts_search_fields(&schema_fields, &fts_fields);
+
         // Iterator for indexed texts only
         for item in indexed_text.iter() {
             let mut indexed_search = Vec::new();
-            for field in &schema_fields {
-                if !fts_fields.contains(&field.name().to_lowercase()) {
-                    continue;
-                }
-                if !field.data_type().eq(&DataType::Utf8) || field.name().starts_with('@') {
-                    continue;
-                }
+            for field in &fts_search_fields {
                 // since data in file can be in uppercase, we need to use ILIKE
-                indexed_search.push(format!("\"{}\" ILIKE '%{}%'", field.name(), item.1));
+                indexed_search.push(format!("\"{}\" ILIKE '%{}%'", field, item.1));
                 // add full text field to meta fields
-                meta.fields.push(field.name().to_string());
+                meta.fields.push(field.clone());
                 fts_terms.insert(item.1.clone());
             }
             if indexed_search.is_empty() {
@@ -384,20 +394,19 @@ impl Sql {
 
         for item in fulltext.iter() {
             let mut fulltext_search = Vec::new();
-            for field in &schema_fields {
-                if !fts_fields.contains(&field.name().to_lowercase()) {
-                    continue;
-                }
-                if !field.data_type().eq(&DataType::Utf8) || field.name().starts_with('@') {
-                    continue;
-                }
+            for field in &fts_search_fields {
                 let mut func = "LIKE";
-                if ignore_case || item.0.to_lowercase().contains("_ignore_case") {
+                if ignore_case
+                    || item
+                        .0
+                        .to_lowercase()
+                        .starts_with(MATCH_ALL_RAW_IGNORE_CASE_UDF_NAME)
+                {
                     func = "ILIKE";
                 }
-                fulltext_search.push(format!("\"{}\" {} '%{}%'", field.name(), func, item.1));
+                fulltext_search.push(format!("\"{}\" {} '%{}%'", field, func, item.1));
                 // add full text field to meta fields
-                meta.fields.push(field.name().to_string());
+                meta.fields.push(field.clone());
             }
             if fulltext_search.is_empty() {
                 return Err(Error::ErrorCode(ErrorCodes::FullTextSearchFieldNotFound));
@@ -758,6 +767,55 @@ pub fn pickup_where(sql: &str, meta: Option<MetaSql>) -> Result<Option<String>,
     Ok(Some(where_str))
 }
 
+/// Collects the match_all search terms of a where token into `fulltext` (raw variants) and
+/// `indexed_text` (match_all), trimming the terms when configured.
+fn extract_match_all_terms(
+    token: &str,
+    trim_term: bool,
+    trim_raw_term: bool,
+    fulltext: &mut Vec<(String, String)>,
+    indexed_text: &mut Vec<(String, String)>,
+) {
+    let trim = |term: &str, enabled: bool| {
+        if enabled {
+            term.trim().to_string()
+        } else {
+            term.to_string()
+        }
+    };
+    for cap in RE_MATCH_ALL_RAW.captures_iter(token) {
+        fulltext.push((cap[0].to_string(), trim(&cap[1], trim_raw_term)));
+    }
+    for cap in RE_MATCH_ALL_RAW_IGNORE_CASE.captures_iter(token) {
+        fulltext.push((
+            cap[0].to_string(),
+            trim(&cap[1], trim_raw_term).to_lowercase(),
+        ));
+    }
+    for cap in RE_MATCH_ALL.captures_iter(token) {
+        // since `terms` are indexed in lowercase
+        indexed_text.push((cap[0].to_string(), trim(&cap[1], trim_term).to_lowercase()));
+    }
+}
+
+/// Returns the names of the Utf8 schema fields configured for full text search,
+/// comparing the configured names with the schema case-insensitively.
+fn get_fts_search_fields(schema_fields: &[FieldRef], fts_fields: &[String]) -> Vec<String> {
+    let fts_fields = fts_fields
+        .iter()
+        .map(|f| f.to_lowercase())
+        .collect::<HashSet<_>>();
+    schema_fields
+        .iter()
+        .filter(|field| {
+            fts_fields.contains(&field.name().to_lowercase())
+                && field.data_type().eq(&DataType::Utf8)
+                && !field.name().starts_with('@')
+        })
+        .map(|field| field.name().to_string())
+        .collect()
+}
+
 fn closing_brace_index(opening_brace_index: usize, expr: &str) -> Option<usize> {
     let mut brace_count = 0;
     for (i, c) in expr[opening_brace_index..].chars().enumerate() {
@@ -942,13 +1000,21 @@ fn checking_inverted_index_inner(index_fields: &HashSet<&String>, expr: &Expr) -
             pattern: _,
             escape_char: _,
         } => checking_inverted_index_inner(index_fields, expr),
-        Expr::Function(f) => f.name.to_string().starts_with("match_all"),
+        Expr::Function(f) => is_match_all(&f.name.to_string()),
         _ => false,
     }
 }
 
+fn is_match_all(name: &str) -> bool {
+    MATCH_ALL_UDF_NAMES.contains(&name)
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use datafusion::arrow::datatypes::Field;
+
     use super::*;
 
     #[tokio::test]
@@ -1309,4 +1375,89 @@ mod tests {
             assert_eq!(res, ok);
         }
     }
+
+    #[test]
+    fn test_get_fts_search_fields() {
+        let schema_fields = vec![
+            Arc::new(Field::new("message", DataType::Utf8, true)),
+            Arc::new(Field::new("Log", DataType::Utf8, true)),
+            Arc::new(Field::new("code", DataType::Int64, true)),
+            Arc::new(Field::new("@meta", DataType::Utf8, true)),
+            Arc::new(Field::new("other", DataType::Utf8, true)),
+        ];
+        let fts_fields = vec![
+            "Message".to_string(),
+            "log".to_string(),
+            "code".to_string(),
+            "@meta".to_string(),
+        ];
+        assert_eq!(
+            get_fts_search_fields(&schema_fields, &fts_fields),
+            vec!["message".to_string(), "Log".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_extract_match_all_terms() {
+        let tokens = [
+            "match_all(' Error ')",
+            "match_all_raw(' error ')",
+            "match_all_raw_ignore_case(' Error ')",
+        ];
+
+        // default: trim match_all, keep whitespace for the raw variants
+        let mut fulltext = Vec::new();
+        let mut indexed_text = Vec::new();
+        for token in tokens {
+            extract_match_all_terms(token, true, false, &mut fulltext, &mut indexed_text);
+        }
+        assert_eq!(
+            indexed_text,
+            vec![("match_all(' Error ')".to_string(), "error".to_string())]
+        );
+        assert_eq!(
+            fulltext,
+            vec![
+                (
+                    "match_all_raw(' error ')".to_string(),
+                    " error ".to_string()
+                ),
+                (
+                    "match_all_raw_ignore_case(' Error ')".to_string(),
+                    " error ".to_string()
+                ),
+            ]
+        );
+
+        // trimming disabled for match_all, enabled for the raw variants
+        let mut fulltext = Vec::new();
+        let mut indexed_text = Vec::new();
+        for token in tokens {
+            extract_match_all_terms(token, false, true, &mut fulltext, &mut indexed_text);
+        }
+        assert_eq!(
+            indexed_text,
+            vec![("match_all(' Error ')".to_string(), " error ".to_string())]
+        );
+        assert_eq!(
+            fulltext,
+            vec![
+                ("match_all_raw(' error ')".to_string(), "error".to_string()),
+                (
+                    "match_all_raw_ignore_case(' Error ')".to_string(),
+                    "error".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_match_all() {
+        for name in MATCH_ALL_UDF_NAMES {
+            assert!(is_match_all(name));
+        }
+        assert!(!is_match_all("MATCH_ALL"));
+        assert!(!is_match_all("str_match"));
+        assert!(!is_match_all("match_all_foo"));
+    }
 }